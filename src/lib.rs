@@ -0,0 +1,16 @@
+//! A simple interface into [GitHub]'s search API, for pulling repository
+//! and user statistics.
+//!
+//! [GitHub]: https://github.com/
+
+use std::error::Error;
+
+mod search;
+
+pub use search::{CodeResult, Commit, CommitDetails, Issue, Query, Repository, Search, SearchArea, SearchError, SearchResults, User};
+
+#[cfg(feature = "blocking")]
+pub use search::Pages;
+
+/// A catch-all result type used throughout this crate.
+pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
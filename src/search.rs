@@ -1,8 +1,8 @@
 use std::fmt;
 use std::error::Error;
 
+use reqwest::header::{ACCEPT, AUTHORIZATION, USER_AGENT};
 use serde::Deserialize;
-use serde_json::Value;
 
 use crate::Result;
 
@@ -10,20 +10,130 @@ pub use query::Query;
 
 mod query;
 
+/// Sent with every request; GitHub rejects anonymous requests that lack a
+/// `User-Agent` header entirely.
+const DEFAULT_USER_AGENT: &str = concat!("github-stats-rs/", env!("CARGO_PKG_VERSION"));
+
+/// GitHub's search API only ever returns the first 1000 matches, no
+/// matter how large `total_count` is; requesting a page past that
+/// returns a 422.
+const MAX_SEARCHABLE_RESULTS: usize = 1000;
+
+fn ceil_div(numerator: usize, denominator: usize) -> usize {
+    numerator.div_ceil(denominator)
+}
+
+/// Number of `per_page`-sized pages needed to cover `total_count` results,
+/// capped at GitHub's 1000-result search limit so callers never walk into
+/// a page that would 422.
+fn capped_total_pages(total_count: usize, per_page: usize) -> usize {
+    let pages_for_total = ceil_div(total_count, per_page);
+    let pages_for_cap = ceil_div(MAX_SEARCHABLE_RESULTS, per_page);
+    pages_for_total.min(pages_for_cap)
+}
+
+/// Stamps `per_page` onto freshly deserialized results, since GitHub's
+/// response doesn't echo back the page size that was requested.
+fn with_per_page<T>(mut results: TypedResults<T>, per_page: usize) -> TypedResults<T> {
+    results.per_page = per_page;
+    results
+}
+
+/// The GitHub search endpoint a [`Search`] targets, and the shape of the
+/// items it returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchArea {
+    Issues,
+    Repositories,
+    Users,
+    Code,
+    Commits,
+}
+
+impl SearchArea {
+    fn endpoint(self) -> &'static str {
+        match self {
+            SearchArea::Issues => "issues",
+            SearchArea::Repositories => "repositories",
+            SearchArea::Users => "users",
+            SearchArea::Code => "code",
+            SearchArea::Commits => "commits",
+        }
+    }
+}
+
+impl fmt::Display for SearchArea {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.endpoint())
+    }
+}
+
+/// An issue or pull request, returned by a [`SearchArea::Issues`] search.
+#[derive(Debug, Deserialize)]
+pub struct Issue {
+    pub number: u64,
+    pub title: String,
+    pub state: String,
+    pub html_url: String,
+}
+
+/// A repository, returned by a [`SearchArea::Repositories`] search.
+#[derive(Debug, Deserialize)]
+pub struct Repository {
+    pub full_name: String,
+    pub html_url: String,
+    pub stargazers_count: u64,
+    pub forks_count: u64,
+    pub language: Option<String>,
+}
+
+/// A user or org, returned by a [`SearchArea::Users`] search.
+#[derive(Debug, Deserialize)]
+pub struct User {
+    pub login: String,
+    #[serde(rename = "type")]
+    pub user_type: String,
+    pub html_url: String,
+}
+
+/// A file, returned by a [`SearchArea::Code`] search.
+#[derive(Debug, Deserialize)]
+pub struct CodeResult {
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    pub html_url: String,
+}
+
+/// A commit, returned by a [`SearchArea::Commits`] search.
+#[derive(Debug, Deserialize)]
+pub struct Commit {
+    pub sha: String,
+    pub html_url: String,
+    pub commit: CommitDetails,
+}
+
+/// The part of a [`Commit`] describing the commit itself, as opposed to
+/// where it lives.
+#[derive(Debug, Deserialize)]
+pub struct CommitDetails {
+    pub message: String,
+}
+
 /// Uses [Github]'s search API.
 ///
 /// # Example
 /// ## Get merged PRs
 ///
 /// ```
-/// use github_stats::{Query, Search};
+/// use github_stats::{Query, Search, SearchArea};
 ///
 /// let query = Query::new()
 ///     .repo("rust-lang", "rust")
 ///     .is("pr")
 ///     .is("merged");
 ///
-/// let results = Search::new("issues", &query)
+/// let results = Search::new(SearchArea::Issues, &query)
 ///     .per_page(10)
 ///     .page(1)
 ///     .search();
@@ -36,16 +146,63 @@ mod query;
 ///
 /// [Github]: https://github.com/
 pub struct Search {
-    search_area: Option<String>,
+    search_area: Option<SearchArea>,
     query: Option<String>,
     per_page: usize,
     page: usize,
+    token: Option<String>,
+    sort: Option<String>,
+    order: Option<String>,
 }
 
+/// Results of a search, typed according to the [`SearchArea`] it came
+/// from.
+///
+/// Only reachable through a [`SearchResults`] variant; its fields stay
+/// private so [`SearchResults`]'s accessor methods are the only way in.
 #[derive(Debug, Deserialize)]
-pub struct SearchResults {
+pub struct TypedResults<T> {
     total_count: u64,
-    items: Vec<Value>,
+    incomplete_results: bool,
+    items: Vec<T>,
+    #[serde(skip)]
+    per_page: usize,
+}
+
+impl<T> TypedResults<T> {
+    fn total_count(&self) -> u64 {
+        self.total_count
+    }
+
+    /// Whether GitHub gave up before fully computing results, e.g.
+    /// because the query timed out.
+    fn incomplete_results(&self) -> bool {
+        self.incomplete_results
+    }
+
+    fn items(&self) -> &Vec<T> {
+        &self.items
+    }
+
+    /// Total number of pages of `per_page` results, derived from
+    /// `total_count` and capped at GitHub's 1000-result search limit
+    /// (mirroring [`Pages`], which stops at the same boundary).
+    fn total_pages(&self) -> usize {
+        if self.per_page == 0 {
+            0
+        } else {
+            capped_total_pages(self.total_count as usize, self.per_page)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SearchResults {
+    Issues(TypedResults<Issue>),
+    Repositories(TypedResults<Repository>),
+    Users(TypedResults<User>),
+    Code(TypedResults<CodeResult>),
+    Commits(TypedResults<Commit>),
 }
 
 #[derive(Debug)]
@@ -61,15 +218,9 @@ impl Error for SearchError {}
 
 impl Search {
     /// Creates a new search configuration.
-    ///
-    /// # Available Choices for `area`
-    /// - `"issues"`
-    /// *More choices will be made available as this project continues.*
-    /// *Other choices, such as `"users"`, are technically possible, but*
-    /// *are not yet properly supported.*
-    pub fn new(area: &str, query: &Query) -> Self {
+    pub fn new(area: SearchArea, query: &Query) -> Self {
         Search {
-            search_area: Some(String::from(area)),
+            search_area: Some(area),
             query: Some(query.to_string()),
             ..Default::default()
         }
@@ -87,29 +238,197 @@ impl Search {
         self
     }
 
+    /// Authenticates requests with a GitHub personal access token.
+    ///
+    /// Anonymous search requests are capped at ~10 requests/minute and
+    /// can't see private repositories; sending a token via
+    /// `Authorization: token <PAT>` raises that limit to 30 req/min and
+    /// lets the search see anything the token's owner can.
+    pub fn token(mut self, token: &str) -> Self {
+        self.token = Some(String::from(token));
+        self
+    }
+
+    /// Sorts results by the given field, e.g. `"stars"`, `"forks"`,
+    /// `"updated"`, or `"comments"`. Defaults to best-match relevance.
+    pub fn sort(mut self, sort: &str) -> Self {
+        self.sort = Some(String::from(sort));
+        self
+    }
+
+    /// Orders results `"asc"` or `"desc"`. Only meaningful alongside
+    /// [`Search::sort`].
+    pub fn order(mut self, order: &str) -> Self {
+        self.order = Some(String::from(order));
+        self
+    }
+
     /// Moves one page forward.
     pub fn next_page(&mut self) {
-        if self.page < std::usize::MAX {
+        if self.page < usize::MAX {
             self.page += 1; 
         }
     }
 
     /// Moves one page backward.
     pub fn prev_page(&mut self) {
-        if self.page > std::usize::MIN {
+        if self.page > usize::MIN {
             self.page -= 1;
         }
     }
 
-    /// Runs the search.
+    /// Consumes this search into an iterator over every page of results.
+    ///
+    /// Each call to [`Iterator::next`] refetches with an advanced `page`,
+    /// stopping once the last page is reached or GitHub's 1000-result
+    /// search cap is hit, whichever comes first.
+    #[cfg(feature = "blocking")]
+    pub fn pages(self) -> Pages {
+        Pages {
+            search: self,
+            total_pages: None,
+            done: false,
+        }
+    }
+
+    /// Runs the search using a blocking client.
+    ///
+    /// Requires the `blocking` feature (on by default). On targets
+    /// without blocking I/O, such as `wasm32-unknown-unknown`, disable
+    /// default features and use [`Search::search_async`] instead.
+    #[cfg(feature = "blocking")]
     pub fn search(&self) -> Result<SearchResults> {
-        if let (Some(_), Some(_)) = (self.search_area.as_ref(), self.query.as_ref()) {
-            let results: SearchResults = reqwest::get(&self.to_string())?.json()?;
-            Ok(results)
+        if let (Some(area), Some(query)) = (self.search_area, self.query.as_ref()) {
+            query::validate(query)?;
+
+            let client = reqwest::blocking::Client::new();
+            let mut request = client
+                .get(self.to_string())
+                .header(USER_AGENT, DEFAULT_USER_AGENT);
+
+            if let Some(token) = &self.token {
+                request = request
+                    .header(AUTHORIZATION, format!("token {}", token))
+                    .header(ACCEPT, "application/vnd.github+json");
+            }
+
+            let response = request.send()?;
+            Ok(match area {
+                SearchArea::Issues => {
+                    SearchResults::Issues(with_per_page(response.json()?, self.per_page))
+                }
+                SearchArea::Repositories => {
+                    SearchResults::Repositories(with_per_page(response.json()?, self.per_page))
+                }
+                SearchArea::Users => {
+                    SearchResults::Users(with_per_page(response.json()?, self.per_page))
+                }
+                SearchArea::Code => {
+                    SearchResults::Code(with_per_page(response.json()?, self.per_page))
+                }
+                SearchArea::Commits => {
+                    SearchResults::Commits(with_per_page(response.json()?, self.per_page))
+                }
+            })
         } else {
             Err(Box::new(SearchError("Please provide search area and query by using Search::new()".into())))
         }
     }
+
+    /// Runs the search using `reqwest`'s async client.
+    ///
+    /// Unlike [`Search::search`], this doesn't depend on `reqwest`'s
+    /// blocking client, so it works on targets where blocking I/O isn't
+    /// available, such as `wasm32-unknown-unknown` (e.g. Cloudflare
+    /// Workers).
+    pub async fn search_async(&self) -> Result<SearchResults> {
+        if let (Some(area), Some(query)) = (self.search_area, self.query.as_ref()) {
+            query::validate(query)?;
+
+            let client = reqwest::Client::new();
+            let mut request = client
+                .get(self.to_string())
+                .header(USER_AGENT, DEFAULT_USER_AGENT);
+
+            if let Some(token) = &self.token {
+                request = request
+                    .header(AUTHORIZATION, format!("token {}", token))
+                    .header(ACCEPT, "application/vnd.github+json");
+            }
+
+            let response = request.send().await?;
+            Ok(match area {
+                SearchArea::Issues => {
+                    SearchResults::Issues(with_per_page(response.json().await?, self.per_page))
+                }
+                SearchArea::Repositories => {
+                    SearchResults::Repositories(with_per_page(response.json().await?, self.per_page))
+                }
+                SearchArea::Users => {
+                    SearchResults::Users(with_per_page(response.json().await?, self.per_page))
+                }
+                SearchArea::Code => {
+                    SearchResults::Code(with_per_page(response.json().await?, self.per_page))
+                }
+                SearchArea::Commits => {
+                    SearchResults::Commits(with_per_page(response.json().await?, self.per_page))
+                }
+            })
+        } else {
+            Err(Box::new(SearchError("Please provide search area and query by using Search::new()".into())))
+        }
+    }
+}
+
+/// Iterator returned by [`Search::pages`], yielding one [`SearchResults`]
+/// per page until the results are exhausted.
+#[cfg(feature = "blocking")]
+pub struct Pages {
+    search: Search,
+    total_pages: Option<usize>,
+    done: bool,
+}
+
+#[cfg(feature = "blocking")]
+impl Iterator for Pages {
+    type Item = Result<SearchResults>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let results = match self.search.search() {
+            Ok(results) => results,
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e));
+            }
+        };
+
+        let per_page = self.search.per_page.max(1);
+        let total_pages = *self
+            .total_pages
+            .get_or_insert_with(|| capped_total_pages(results.total_count() as usize, per_page));
+
+        if self.search.page >= total_pages {
+            self.done = true;
+        } else {
+            self.search.next_page();
+        }
+
+        Some(Ok(results))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl IntoIterator for Search {
+    type Item = Result<SearchResults>;
+    type IntoIter = Pages;
+
+    fn into_iter(self) -> Pages {
+        self.pages()
+    }
 }
 
 impl SearchResults {
@@ -118,12 +437,77 @@ impl SearchResults {
     /// This ignores `per_page`. If you only want the total count, it is
     /// recommended that you set `per_page` to `1` to shrink results size.
     pub fn total_count(&self) -> u64 {
-        self.total_count
+        match self {
+            SearchResults::Issues(r) => r.total_count(),
+            SearchResults::Repositories(r) => r.total_count(),
+            SearchResults::Users(r) => r.total_count(),
+            SearchResults::Code(r) => r.total_count(),
+            SearchResults::Commits(r) => r.total_count(),
+        }
     }
 
-    /// Items matching the query.
-    pub fn items(&self) -> &Vec<Value> {
-        &self.items
+    /// Whether GitHub gave up before fully computing results, e.g.
+    /// because the query timed out.
+    pub fn incomplete_results(&self) -> bool {
+        match self {
+            SearchResults::Issues(r) => r.incomplete_results(),
+            SearchResults::Repositories(r) => r.incomplete_results(),
+            SearchResults::Users(r) => r.incomplete_results(),
+            SearchResults::Code(r) => r.incomplete_results(),
+            SearchResults::Commits(r) => r.incomplete_results(),
+        }
+    }
+
+    /// Total number of pages of results, derived from `total_count` and
+    /// the `per_page` the search was configured with.
+    pub fn total_pages(&self) -> usize {
+        match self {
+            SearchResults::Issues(r) => r.total_pages(),
+            SearchResults::Repositories(r) => r.total_pages(),
+            SearchResults::Users(r) => r.total_pages(),
+            SearchResults::Code(r) => r.total_pages(),
+            SearchResults::Commits(r) => r.total_pages(),
+        }
+    }
+
+    /// Issues matching the query, if this came from a [`SearchArea::Issues`] search.
+    pub fn issues(&self) -> Option<&Vec<Issue>> {
+        match self {
+            SearchResults::Issues(r) => Some(r.items()),
+            _ => None,
+        }
+    }
+
+    /// Repositories matching the query, if this came from a [`SearchArea::Repositories`] search.
+    pub fn repositories(&self) -> Option<&Vec<Repository>> {
+        match self {
+            SearchResults::Repositories(r) => Some(r.items()),
+            _ => None,
+        }
+    }
+
+    /// Users matching the query, if this came from a [`SearchArea::Users`] search.
+    pub fn users(&self) -> Option<&Vec<User>> {
+        match self {
+            SearchResults::Users(r) => Some(r.items()),
+            _ => None,
+        }
+    }
+
+    /// Code matching the query, if this came from a [`SearchArea::Code`] search.
+    pub fn code(&self) -> Option<&Vec<CodeResult>> {
+        match self {
+            SearchResults::Code(r) => Some(r.items()),
+            _ => None,
+        }
+    }
+
+    /// Commits matching the query, if this came from a [`SearchArea::Commits`] search.
+    pub fn commits(&self) -> Option<&Vec<Commit>> {
+        match self {
+            SearchResults::Commits(r) => Some(r.items()),
+            _ => None,
+        }
     }
 }
 
@@ -134,17 +518,16 @@ impl Default for Search {
             query: None,
             per_page: 10,
             page: 1,
+            token: None,
+            sort: None,
+            order: None,
         }
     }
 }
 
 impl fmt::Display for Search {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let search_area: &str = if let Some(area) = &self.search_area {
-            area
-        } else {
-            ""
-        };
+        let search_area: &str = self.search_area.map(SearchArea::endpoint).unwrap_or("");
         let query: &str = if let Some(query) = &self.query {
             query
         } else {
@@ -154,7 +537,17 @@ impl fmt::Display for Search {
             f,
             "https://api.github.com/search/{0}?per_page={1}&page={2}&q={3}",
             search_area, self.per_page, self.page, query,
-        )
+        )?;
+
+        if let Some(sort) = &self.sort {
+            write!(f, "&sort={}", sort)?;
+        }
+
+        if let Some(order) = &self.order {
+            write!(f, "&order={}", order)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -163,8 +556,25 @@ mod tests {
     use super::*;
 
     #[test]
+    #[cfg(feature = "blocking")]
     fn err_on_none() {
         let default_search = Search::default().search();
         assert!(default_search.is_err(), "should be Err, due to missing search area and query")
     }
+
+    #[test]
+    fn capped_total_pages_stays_under_result_cap() {
+        // Fewer results than a single page of the cap: no capping needed.
+        assert_eq!(capped_total_pages(5, 10), 1);
+
+        // Evenly divides the 1000-result cap.
+        assert_eq!(capped_total_pages(5_000, 10), 100);
+
+        // total_count far exceeds the cap: stop at the cap, not total_count.
+        assert_eq!(capped_total_pages(50_000, 10), 100);
+
+        // per_page doesn't evenly divide 1000: round the cap up, same as total_count.
+        assert_eq!(capped_total_pages(50_000, 30), 34);
+        assert_eq!(capped_total_pages(20, 30), 1);
+    }
 }
@@ -0,0 +1,177 @@
+use std::fmt;
+
+use crate::Result;
+
+use super::SearchError;
+
+/// GitHub rejects search queries longer than this many characters.
+const MAX_QUERY_LENGTH: usize = 256;
+
+/// GitHub rejects search queries with more than this many `AND`/`OR`/`NOT`
+/// operators.
+const MAX_OPERATORS: usize = 5;
+
+/// Builds up a query string for [`Search`](super::Search).
+///
+/// # Example
+///
+/// ```
+/// use github_stats::Query;
+///
+/// let query = Query::new()
+///     .repo("rust-lang", "rust")
+///     .is("pr")
+///     .is("merged");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Query {
+    parts: Vec<String>,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Query::default()
+    }
+
+    /// Restricts the search to a single repository.
+    pub fn repo(mut self, owner: &str, name: &str) -> Self {
+        self.parts.push(format!("repo:{}/{}", owner, name));
+        self
+    }
+
+    /// Adds an `is:` qualifier, e.g. `is("pr")` or `is("merged")`.
+    pub fn is(mut self, qualifier: &str) -> Self {
+        self.parts.push(format!("is:{}", qualifier));
+        self
+    }
+
+    /// Restricts the search to a single user or org.
+    pub fn user(mut self, user: &str) -> Self {
+        self.parts.push(format!("user:{}", user));
+        self
+    }
+
+    /// Excludes a repository, emitting `-repo:owner/name`.
+    pub fn not_repo(mut self, owner: &str, name: &str) -> Self {
+        self.parts.push(format!("-repo:{}/{}", owner, name));
+        self
+    }
+
+    /// Excludes a user or org, emitting `-user:user`.
+    pub fn not_user(mut self, user: &str) -> Self {
+        self.parts.push(format!("-user:{}", user));
+        self
+    }
+
+    /// Excludes an `is:` qualifier, emitting `-is:qualifier`.
+    pub fn not_is(mut self, qualifier: &str) -> Self {
+        self.parts.push(format!("-is:{}", qualifier));
+        self
+    }
+
+    /// Excludes an arbitrary `qualifier:value` pair, for qualifiers this
+    /// builder doesn't have a dedicated `not_*` method for.
+    pub fn exclude(mut self, qualifier: &str, value: &str) -> Self {
+        self.parts.push(format!("-{}:{}", qualifier, value));
+        self
+    }
+
+    /// Excludes as many `-qualifier:value` terms from `values` as fit
+    /// within GitHub's 256-character query budget, dropping the rest
+    /// rather than building a query GitHub would reject.
+    pub fn exclude_many(mut self, qualifier: &str, values: &[&str]) -> Self {
+        for value in values {
+            let candidate = self.clone().exclude(qualifier, value).to_string();
+            if candidate.len() > MAX_QUERY_LENGTH {
+                break;
+            }
+            self = self.exclude(qualifier, value);
+        }
+        self
+    }
+
+    /// Builds the query string, failing if it exceeds GitHub's length or
+    /// operator limits instead of silently sending a request GitHub would
+    /// 422 on.
+    pub fn try_build(&self) -> Result<String> {
+        let built = self.to_string();
+        validate(&built)?;
+        Ok(built)
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.parts.join(" "))
+    }
+}
+
+/// Checks a built query string against GitHub's length and operator
+/// limits. Shared by [`Query::try_build`] and [`super::Search`], which
+/// validates before sending a request built from a raw query string.
+pub(crate) fn validate(query: &str) -> Result<()> {
+    if query.len() > MAX_QUERY_LENGTH {
+        return Err(Box::new(SearchError(format!(
+            "query is {} characters long, but GitHub's search API caps queries at {} characters",
+            query.len(),
+            MAX_QUERY_LENGTH
+        ))));
+    }
+
+    let operator_count = query
+        .split_whitespace()
+        .filter(|word| matches!(*word, "AND" | "OR" | "NOT"))
+        .count();
+
+    if operator_count > MAX_OPERATORS {
+        return Err(Box::new(SearchError(format!(
+            "query has {} AND/OR/NOT operators, but GitHub's search API allows at most {}",
+            operator_count, MAX_OPERATORS
+        ))));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_build_errors_past_length_limit() {
+        let long_value = "a".repeat(300);
+        let query = Query::new().exclude("user", &long_value);
+        assert!(query.try_build().is_err());
+    }
+
+    #[test]
+    fn try_build_allows_short_queries() {
+        let query = Query::new().repo("rust-lang", "rust").not_user("spammer");
+        assert!(query.try_build().is_ok());
+    }
+
+    #[test]
+    fn validate_allows_up_to_five_operators() {
+        assert!(validate("a AND b AND c AND d AND e AND f").is_ok());
+    }
+
+    #[test]
+    fn validate_errors_past_five_operators() {
+        assert!(validate("a AND b AND c AND d AND e AND f AND g").is_err());
+    }
+
+    #[test]
+    fn exclude_many_stops_at_budget() {
+        let long_value = "a".repeat(50);
+        let values: Vec<&str> = vec![&long_value; 10];
+
+        let query = Query::new().exclude_many("user", &values);
+        let built = query.to_string();
+
+        assert!(built.len() <= MAX_QUERY_LENGTH);
+        assert!(
+            built.matches(long_value.as_str()).count() < values.len(),
+            "expected exclude_many to drop terms once the budget ran out"
+        );
+    }
+}